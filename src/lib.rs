@@ -23,16 +23,10 @@ pub fn get_string_from_image(img_path: String) -> String {
         path: String::from(img_path),
         ndarray: ndarray::Array3::<u8>::zeros((200, 200, 3)), // example: creates an 100x100 pixel image with 3 colour channels (RGB)
     };
-    let mut image_to_string_args = rust_tesseract::Args {
-        out_filename: "out",
-        lang: "chi_sim",
-        config: std::collections::HashMap::new(),
-        dpi: 150,
-        boxfile: false,
-    };
-
-    image_to_string_args.config.insert("psm", "6");
-    image_to_string_args.config.insert("oem", "3");
+    let mut image_to_string_args = rust_tesseract::Args::new();
+    image_to_string_args.lang = String::from("chi_sim");
+    image_to_string_args.psm = rust_tesseract::PageSegMode::SingleBlock;
+    image_to_string_args.oem = rust_tesseract::OcrEngineMode::Default;
 
     let output = crate::rust_tesseract::image_to_string(&img, image_to_string_args);
     return output.output_string;
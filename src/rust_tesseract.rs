@@ -7,9 +7,12 @@ use std::env::current_dir;
 use std::fmt;
 use std::fs;
 use std::io::BufRead;
+#[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
+use std::path::PathBuf;
 use std::process::{Command, Stdio};
 use std::string::ToString;
+use tempfile::tempdir;
 
 use crate::error::ImageFormatError;
 use crate::error::ImageNotFoundError;
@@ -20,13 +23,28 @@ const FORMATS: [&'static str; 10] = [
     "JPEG", "JPG", "PNG", "PBM", "PGM", "PPM", "TIFF", "BMP", "GIF", "WEBP",
 ];
 
+/// Selects how OCR is actually executed: by spawning the `tesseract` CLI, or
+/// by calling `libtesseract` in-process through FFI. Stored alongside the
+/// `TesseractPath` so callers can switch at runtime.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Shell out to the `tesseract` binary (default).
+    Subprocess,
+    /// Link `libtesseract` directly and hand the image pixels to the library.
+    Library,
+}
+
 pub struct TesseractPath {
     pub path: Option<String>,
+    pub backend: Backend,
 }
 
 impl TesseractPath {
     pub fn new() -> TesseractPath {
-        return TesseractPath { path: None };
+        return TesseractPath {
+            path: None,
+            backend: Backend::Subprocess,
+        };
     }
 
     pub fn use_current_dir() -> TesseractPath {
@@ -34,25 +52,16 @@ impl TesseractPath {
 
         match p {
             Ok(_p) => {
-                if cfg!(target_os = "windows") {
-                    let r = format!(
-                        "{}/tesseract/tesseract.exe",
-                        _p.as_os_str()
-                            .to_str()
-                            .unwrap_or("./tesseract/tesseract.exe")
-                    );
-                    return TesseractPath {
-                        path: Some(String::from(r)),
-                    };
+                let binary = if cfg!(target_os = "windows") {
+                    "tesseract.exe"
                 } else {
-                    let r = format!(
-                        "{}/tesseract/tesseract",
-                        _p.as_os_str().to_str().unwrap_or("./tesseract/tesseract")
-                    );
-                    return TesseractPath {
-                        path: Some(String::from(r)),
-                    };
-                }
+                    "tesseract"
+                };
+                let r = _p.join("tesseract").join(binary);
+                return TesseractPath {
+                    path: Some(r.to_string_lossy().into_owned()),
+                    backend: Backend::Subprocess,
+                };
             }
             Err(_) => {
                 return TesseractPath::new();
@@ -61,17 +70,22 @@ impl TesseractPath {
     }
 
     pub fn use_certain_path(s: String) -> TesseractPath {
-        return TesseractPath { path: Some(s) };
+        return TesseractPath {
+            path: Some(s),
+            backend: Backend::Subprocess,
+        };
     }
 
     pub fn use_default() -> TesseractPath {
         if cfg!(target_os = "windows") {
             return TesseractPath {
                 path: Some(String::from("tesseract.exe")),
+                backend: Backend::Subprocess,
             };
         } else {
             return TesseractPath {
                 path: Some(String::from("tesseract")),
+                backend: Backend::Subprocess,
             };
         }
     }
@@ -79,6 +93,10 @@ impl TesseractPath {
     pub fn set_tesseract_path(&mut self, s: String) {
         self.path = Some(s);
     }
+
+    pub fn set_backend(&mut self, backend: Backend) {
+        self.backend = backend;
+    }
 }
 
 pub fn get_tesseract_installed_path() -> Option<String> {
@@ -100,6 +118,23 @@ pub fn set_tesseract_installed_path(s: String) {
     }
 }
 
+pub fn get_backend() -> Backend {
+    match TESSERACT.read() {
+        Ok(t) => t.backend,
+        Err(_) => Backend::Subprocess,
+    }
+}
+
+pub fn set_backend(backend: Backend) {
+    let t = TESSERACT.write();
+    match t {
+        Ok(mut t0) => t0.set_backend(backend),
+        Err(_) => {
+            println!("error reset backend")
+        }
+    }
+}
+
 fn check_if_installed() -> bool {
     let p = get_tesseract_installed_path();
     match p {
@@ -125,7 +160,7 @@ pub struct ModelOutput {
     pub output_bytes: Vec<u8>,
     pub output_dict: MultiMap<String, String>,
     pub output_string: String,
-    pub output_dataframe: Vec<Series>,
+    pub output_dataframe: DataFrame,
 }
 
 impl ModelOutput {
@@ -135,7 +170,7 @@ impl ModelOutput {
             output_bytes: Vec::new(),
             output_dict: MultiMap::new(),
             output_string: String::new(),
-            output_dataframe: Vec::new(),
+            output_dataframe: DataFrame::default(),
         }
     }
 }
@@ -146,25 +181,116 @@ impl fmt::Display for ModelOutput {
     }
 }
 
+/// Tesseract page segmentation mode (`--psm`). Values mirror tesseract's
+/// `0`..=`13`, so invalid modes are unrepresentable.
+#[derive(Clone, Copy)]
+pub enum PageSegMode {
+    OsdOnly = 0,
+    AutoOsd = 1,
+    AutoOnly = 2,
+    Auto = 3,
+    SingleColumn = 4,
+    SingleBlockVertText = 5,
+    SingleBlock = 6,
+    SingleLine = 7,
+    SingleWord = 8,
+    CircleWord = 9,
+    SingleChar = 10,
+    SparseText = 11,
+    SparseTextOsd = 12,
+    RawLine = 13,
+}
+
+impl PageSegMode {
+    fn as_arg(&self) -> String {
+        (*self as u8).to_string()
+    }
+}
+
+/// Tesseract OCR engine mode (`--oem`).
+#[derive(Clone, Copy)]
+pub enum OcrEngineMode {
+    TesseractOnly = 0,
+    LstmOnly = 1,
+    TesseractLstmCombined = 2,
+    Default = 3,
+}
+
+impl OcrEngineMode {
+    fn as_arg(&self) -> String {
+        (*self as u8).to_string()
+    }
+}
+
+/// Selects which tesseract output format to produce and read back. `PlainText`
+/// keeps the flat `.txt` behaviour; the others enable the matching
+/// `tessedit_create_*` config flag and read the corresponding file.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    PlainText,
+    Hocr,
+    Alto,
+    Pdf,
+    Tsv,
+}
+
+impl OutputFormat {
+    /// The `tessedit_create_*` config flag to enable, if any.
+    fn config_flag(&self) -> Option<&'static str> {
+        match self {
+            OutputFormat::PlainText => None,
+            OutputFormat::Hocr => Some("tessedit_create_hocr"),
+            OutputFormat::Alto => Some("tessedit_create_alto"),
+            OutputFormat::Pdf => Some("tessedit_create_pdf"),
+            OutputFormat::Tsv => Some("tessedit_create_tsv"),
+        }
+    }
+
+    /// The extension tesseract appends to the output-base for this format.
+    fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::PlainText => "txt",
+            OutputFormat::Hocr => "hocr",
+            OutputFormat::Alto => "xml",
+            OutputFormat::Pdf => "pdf",
+            OutputFormat::Tsv => "tsv",
+        }
+    }
+
+    /// Whether the output file is UTF-8 text that belongs in `output_string`.
+    fn is_text(&self) -> bool {
+        !matches!(self, OutputFormat::Pdf)
+    }
+}
+
 #[derive(Clone)]
 pub struct Args {
-    pub out_filename: &'static str,
-    pub lang: &'static str,
-    pub config: HashMap<&'static str, &'static str>,
+    pub lang: String,
+    pub config: HashMap<String, String>,
     pub dpi: i32,
     pub boxfile: bool,
+    pub psm: PageSegMode,
+    pub oem: OcrEngineMode,
+    pub output_format: OutputFormat,
 }
 
 impl Args {
     pub fn new() -> Args {
         Args {
             config: HashMap::new(),
-            lang: "eng",
-            out_filename: "out",
+            lang: String::from("eng"),
             dpi: 150,
             boxfile: false,
+            psm: PageSegMode::Auto,
+            oem: OcrEngineMode::Default,
+            output_format: OutputFormat::PlainText,
         }
     }
+
+    /// Set an arbitrary `-c name=value` tesseract config variable.
+    pub fn set_variable(&mut self, name: String, value: String) {
+        self.config.insert(name, value);
+    }
 }
 
 #[derive(Clone)]
@@ -241,13 +367,14 @@ pub fn get_tesseract_version() -> String {
     let p = get_tesseract_installed_path();
     match p {
         Some(p0) => {
-            let command = Command::new(p0)
-                .creation_flags(0x08000000)
+            let mut builder = Command::new(p0);
+            builder
                 .arg("--version")
                 .stdout(Stdio::piped())
-                .stderr(Stdio::piped())
-                .spawn()
-                .unwrap();
+                .stderr(Stdio::piped());
+            #[cfg(target_os = "windows")]
+            builder.creation_flags(0x08000000);
+            let command = builder.spawn().unwrap();
             let output = command.wait_with_output().unwrap();
 
             let out = output.stdout;
@@ -281,18 +408,21 @@ pub fn image_to_data(image: &Image, args: Args) -> ModelOutput {
     box_args.boxfile = true;
     let box_out: ModelOutput = image_to_boxes(&image, box_args);
 
+    // word-level TSV carries the confidence-and-geometry DataFrame. Request TSV
+    // as the output format so tesseract emits `out.tsv` (with no renderer it
+    // would only write the text file, and this pass would read a missing one).
+    let mut tesstable_args = args.clone();
+    tesstable_args.output_format = OutputFormat::Tsv;
+    let tesstable = run_tesseract(&image, &tesstable_args).unwrap_or_else(ModelOutput::new);
+
     let out = ModelOutput {
         output_info: str_out.output_info,
         output_bytes: str_out.output_bytes,
         output_dict: box_out.output_dict,
         output_string: str_out.output_string,
-        output_dataframe: box_out.output_dataframe,
+        output_dataframe: tesstable.output_dataframe,
     };
 
-    let mut tesstable_args = args.clone();
-    tesstable_args.config.insert("-c", "tessedit_create_tsv=1");
-    let _tesstable = run_tesseract(&image, &tesstable_args);
-
     if check_image_format(&image) {
         return out;
     } else {
@@ -317,7 +447,139 @@ pub fn image_to_string(image: &Image, args: Args) -> ModelOutput {
     }
 }
 
+/// Common interface over the subprocess and in-process library backends so
+/// `image_to_string`/`image_to_boxes`/`image_to_data` don't care how the OCR
+/// is actually performed.
+trait OcrBackend {
+    fn run(&self, image: &Image, args: &Args) -> Option<ModelOutput>;
+}
+
+/// Dispatches to the backend currently selected on the global `TesseractPath`.
 fn run_tesseract(image: &Image, args: &Args) -> Option<ModelOutput> {
+    match get_backend() {
+        Backend::Subprocess => SubprocessBackend.run(image, args),
+        Backend::Library => LibraryBackend.run(image, args),
+    }
+}
+
+struct SubprocessBackend;
+
+impl OcrBackend for SubprocessBackend {
+    fn run(&self, image: &Image, args: &Args) -> Option<ModelOutput> {
+        run_tesseract_subprocess(image, args)
+    }
+}
+
+/// In-process backend linking `libtesseract` via the `tesseract` FFI crate.
+/// An image path, when set, is decoded in-process; otherwise the `Image.ndarray`
+/// is handed straight to `SetImage`. Nothing is written to the filesystem and
+/// the `tesseract` CLI need not be on `PATH`.
+struct LibraryBackend;
+
+impl OcrBackend for LibraryBackend {
+    fn run(&self, image: &Image, args: &Args) -> Option<ModelOutput> {
+        run_tesseract_library(image, args)
+    }
+}
+
+fn run_tesseract_library(image: &Image, args: &Args) -> Option<ModelOutput> {
+    use tesseract::plumbing::TessBaseApi;
+
+    // the in-process path only produces flat UTF-8 text; box files and the
+    // richer output formats (TSV/hOCR/ALTO/PDF) are served by the subprocess
+    // backend. Reject those here rather than returning empty structured output.
+    if args.boxfile || args.output_format != OutputFormat::PlainText {
+        println!(
+            "{:?}",
+            "Backend::Library only supports plain-text OCR; use Backend::Subprocess for boxes/TSV/hOCR/ALTO/PDF."
+        );
+        return None;
+    }
+
+    // prefer an on-disk image when a path is given; fall back to the ndarray.
+    let (raw, width, height, channels) = if image.path.len() > 0 {
+        if !check_image_format(image) {
+            println!("{:?}", ImageFormatError);
+            return None;
+        }
+        let path = image.to_string().replace('"', "");
+        match image::open(&path) {
+            Ok(img) => {
+                let rgb = img.to_rgb8();
+                let (w, h) = rgb.dimensions();
+                (rgb.into_raw(), w as usize, h as usize, 3usize)
+            }
+            Err(e) => {
+                println!("Error while loading image: {:?}", e);
+                return None;
+            }
+        }
+    } else if !image.is_empty_ndarray() {
+        let (h, w, c) = image.size_of_ndarray();
+        (image.clone().ndarray.into_raw_vec(), w, h, c)
+    } else {
+        println!("{:?}", ImageNotFoundError);
+        return None;
+    };
+
+    let mut api = TessBaseApi::create();
+    let lang = std::ffi::CString::new(args.lang.as_str()).unwrap();
+    if api.init_4(None, Some(&lang), oem_to_ffi(args.oem)).is_err() {
+        println!("{}", VersionError);
+        return None;
+    }
+
+    // typed page segmentation mode, then any user config variables.
+    let _ = api.set_page_seg_mode(args.psm as u32);
+    for (name, value) in &args.config {
+        let name = std::ffi::CString::new(name.as_str()).unwrap();
+        let val = std::ffi::CString::new(value.as_str()).unwrap();
+        let _ = api.set_variable(&name, &val);
+    }
+
+    // hand the raw RGB bytes to the library without touching disk.
+    if api
+        .set_image(
+            &raw,
+            width as i32,
+            height as i32,
+            channels as i32,
+            (width * channels) as i32,
+        )
+        .is_err()
+    {
+        println!("{:?}", ImageFormatError);
+        return None;
+    }
+    let _ = api.set_source_resolution(args.dpi);
+
+    let text = match api.get_utf8_text() {
+        Ok(t) => t.as_ref().to_string_lossy().into_owned(),
+        Err(_) => String::new(),
+    };
+
+    let mut out = ModelOutput::new();
+    out.output_bytes = text.as_bytes().to_vec();
+    out.output_string = text;
+    Some(out)
+}
+
+/// Map the typed `OcrEngineMode` onto the `tesseract_sys` engine-mode constant
+/// `init_4` expects, so the library backend honors `Args.oem`.
+fn oem_to_ffi(oem: OcrEngineMode) -> tesseract::plumbing::tesseract_sys::TessOcrEngineMode {
+    use tesseract::plumbing::tesseract_sys::{
+        TessOcrEngineMode_OEM_DEFAULT, TessOcrEngineMode_OEM_LSTM_ONLY,
+        TessOcrEngineMode_OEM_TESSERACT_LSTM_COMBINED, TessOcrEngineMode_OEM_TESSERACT_ONLY,
+    };
+    match oem {
+        OcrEngineMode::TesseractOnly => TessOcrEngineMode_OEM_TESSERACT_ONLY,
+        OcrEngineMode::LstmOnly => TessOcrEngineMode_OEM_LSTM_ONLY,
+        OcrEngineMode::TesseractLstmCombined => TessOcrEngineMode_OEM_TESSERACT_LSTM_COMBINED,
+        OcrEngineMode::Default => TessOcrEngineMode_OEM_DEFAULT,
+    }
+}
+
+fn run_tesseract_subprocess(image: &Image, args: &Args) -> Option<ModelOutput> {
     // check if tesseract is installed
     let is_installed: bool = check_if_installed();
     if !is_installed {
@@ -330,20 +592,24 @@ fn run_tesseract(image: &Image, args: &Args) -> Option<ModelOutput> {
         type_of(&Array3::<u8>::zeros((0, 0, 0)))
     );
 
+    // unique temp dir per invocation for the ndarray PNG and the tesseract
+    // output-base, so concurrent calls never race on shared file names. The
+    // directory is removed when `tmp_dir` drops at the end of this function.
+    let tmp_dir = tempdir().expect("Couldn't create temp directory for tesseract output.");
+
     // check if image path or ndarray is provided
     let mut image_arg = String::from("");
     let is_empty_ndarray = &image.is_empty_ndarray();
     if image.path.len() == 0 && !*is_empty_ndarray {
-        // convert ndarray to rgbimage and save image in parent directory
+        // convert ndarray to rgbimage and save image in the temp directory
         let tmp_img = image.clone();
         let i = tmp_img.ndarray_to_image();
-        let working_dir = current_dir().unwrap().as_path().display().to_string();
-        let new_path = [working_dir, String::from("ndarray_converted.png")].join("/");
+        let new_path: PathBuf = tmp_dir.path().join("ndarray_converted.png");
 
         match i.save(&new_path) {
             Ok(_r) => {
                 println!("Image saved: {:?}", new_path);
-                image_arg = new_path;
+                image_arg = new_path.to_string_lossy().into_owned();
             }
             Err(e) => println!("Error while saving image: {:?}", e),
         }
@@ -372,67 +638,46 @@ fn run_tesseract(image: &Image, args: &Args) -> Option<ModelOutput> {
         boxarg = String::from("makebox");
     }
 
-    // check if tesstable command is given
-    let mut tesstable_arg = "tessedit_create_tsv=0";
-    if args.config.contains_key("-c") {
-        tesstable_arg = args.config["-c"];
-    }
-
-    // check if psm and oem flags are set
-    let mut psm = "3";
-    let mut oem = "3";
-    if args.config.contains_key("psm") {
-        psm = args.config["psm"];
-    }
-
-    if args.config.contains_key("oem") {
-        oem = args.config["oem"];
-    }
+    let psm = args.psm.as_arg();
+    let oem = args.oem.as_arg();
 
     println!("the image arg is: {:?}", image_arg);
 
     let tess_path = get_tesseract_installed_path().unwrap();
 
-    let command = if cfg!(target_os = "windows") {
-        Command::new(tess_path)
-            .creation_flags(0x08000000)
-            .arg(image_arg)
-            .arg(args.out_filename)
-            .arg("-l")
-            .arg(args.lang)
-            .arg("--dpi")
-            .arg(args.dpi.to_string())
-            .arg("--psm")
-            .arg(psm)
-            .arg("--oem")
-            .arg(oem)
-            .arg("-c")
-            .arg(tesstable_arg)
-            .arg(boxarg)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .unwrap()
-    } else {
-        Command::new(tess_path)
-            .arg(image_arg)
-            .arg(args.out_filename)
-            .arg("-l")
-            .arg(args.lang)
-            .arg("--dpi")
-            .arg(args.dpi.to_string())
-            .arg("--psm")
-            .arg(psm)
-            .arg("--oem")
-            .arg(oem)
-            .arg("-c")
-            .arg(tesstable_arg)
-            .arg(boxarg)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()
-            .unwrap()
-    };
+    // output-base inside the temp directory; tesseract appends the extension.
+    let out_base = tmp_dir.path().join("out");
+    let out_base_arg = out_base.to_string_lossy().into_owned();
+
+    let mut builder = Command::new(tess_path);
+    builder
+        .arg(image_arg)
+        .arg(&out_base_arg)
+        .arg("-l")
+        .arg(&args.lang)
+        .arg("--dpi")
+        .arg(args.dpi.to_string())
+        .arg("--psm")
+        .arg(psm)
+        .arg("--oem")
+        .arg(oem);
+    // arbitrary `-c name=value` config variables set via `Args::set_variable`.
+    for (name, value) in &args.config {
+        builder.arg("-c").arg(format!("{}={}", name, value));
+    }
+    // enable the config flag for the requested output format (text box mode aside).
+    if !args.boxfile {
+        if let Some(flag) = args.output_format.config_flag() {
+            builder.arg("-c").arg(format!("{}=1", flag));
+        }
+    }
+    builder
+        .arg(boxarg)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    #[cfg(target_os = "windows")]
+    builder.creation_flags(0x08000000);
+    let command = builder.spawn().unwrap();
 
     let output = command.wait_with_output().unwrap();
     println!("{:?}", output);
@@ -455,52 +700,50 @@ fn run_tesseract(image: &Image, args: &Args) -> Option<ModelOutput> {
             .for_each(|line| str_res = format!("{}\n{}", str_res, line.unwrap()));
     }
 
-    // read tesseract output from output file "out.txt"
-    let mut _out_f = String::new();
-    if !args.boxfile {
-        if !args.out_filename.contains(".txt") {
-            _out_f = format!("{}.txt", args.out_filename);
-        } else {
-            _out_f = args.out_filename.to_string();
-        }
-    }
-    // if boxfile is requested -> read from .box file
-    else {
-        if !args.out_filename.contains(".box") {
-            _out_f = format!("{}.box", args.out_filename);
-        } else {
-            _out_f = args.out_filename.to_string();
-        }
-    }
+    // read tesseract output back from the temp directory: ".box" in box mode,
+    // otherwise the extension for the requested output format.
+    let ext = if args.boxfile {
+        "box"
+    } else {
+        args.output_format.extension()
+    };
+    let out_path = out_base.with_extension(ext);
+    let file_bytes = fs::read(&out_path).expect("File reading error. Filename does not exist.");
 
-    let file_output = read_output_file(&_out_f);
+    // keep the string surface for text formats; PDF output is binary.
+    let is_text = args.boxfile || args.output_format.is_text();
+    let file_output = if is_text {
+        String::from_utf8_lossy(&file_bytes).into_owned()
+    } else {
+        String::new()
+    };
 
     // multimap used for box files -> stores character as key and box boundaries as value (or list of values)
     let mut dict = MultiMap::new();
-    let mut df = Vec::new();
     if args.boxfile {
         for line in file_output.lines() {
             if line.contains(" ") {
-                // fill dict
                 let tuple = line.split_once(" ").unwrap();
                 dict.insert(String::from(tuple.0), String::from(tuple.1));
-
-                // fill DataFrame (Vec of Series)
-                let character: &str = &tuple.0;
-                let mut box_boundaries = Vec::new();
-                for num in tuple.1.split(" ") {
-                    let num_int: i32 = num.parse::<i32>().unwrap();
-                    box_boundaries.push(num_int);
-                }
-                let tmp_series = Series::new(character, &box_boundaries);
-                df.push(tmp_series);
             }
         }
     }
 
+    // build the word-level confidence-and-geometry DataFrame whenever a TSV was
+    // produced — either via `OutputFormat::Tsv` or an explicit
+    // `tessedit_create_tsv=1` config variable.
+    let tsv_requested = (args.output_format == OutputFormat::Tsv && !args.boxfile)
+        || args.config.get("tessedit_create_tsv").map(String::as_str) == Some("1");
+    let df = if tsv_requested {
+        let tsv_path = out_base.with_extension("tsv").to_string_lossy().into_owned();
+        parse_tsv(&read_output_file(&tsv_path))
+    } else {
+        DataFrame::default()
+    };
+
     let out = ModelOutput {
         output_info: str_res,
-        output_bytes: file_output.as_bytes().to_vec(),
+        output_bytes: file_bytes,
         output_dict: dict,
         output_string: file_output,
         output_dataframe: df,
@@ -509,6 +752,62 @@ fn run_tesseract(image: &Image, args: &Args) -> Option<ModelOutput> {
     return Some(out);
 }
 
+/// Parse tesseract's TSV output into a `DataFrame` with the fixed tesseract
+/// columns. Numeric columns are typed as `i64` (conf as `f64`); `text` stays a
+/// string. Rows with fewer than 12 tab-separated fields are skipped, and a
+/// field that does not parse becomes `0` (`-1.0` for conf). An empty TSV
+/// yields a `DataFrame` with these columns and no rows.
+fn parse_tsv(tsv: &str) -> DataFrame {
+    let mut level = Vec::new();
+    let mut page_num = Vec::new();
+    let mut block_num = Vec::new();
+    let mut par_num = Vec::new();
+    let mut line_num = Vec::new();
+    let mut word_num = Vec::new();
+    let mut left = Vec::new();
+    let mut top = Vec::new();
+    let mut width = Vec::new();
+    let mut height = Vec::new();
+    let mut conf = Vec::new();
+    let mut text = Vec::new();
+
+    // the first line is the header emitted by tesseract; skip it.
+    for line in tsv.lines().skip(1) {
+        let cols: Vec<&str> = line.split('\t').collect();
+        if cols.len() < 12 {
+            continue;
+        }
+        level.push(cols[0].parse::<i64>().unwrap_or(0));
+        page_num.push(cols[1].parse::<i64>().unwrap_or(0));
+        block_num.push(cols[2].parse::<i64>().unwrap_or(0));
+        par_num.push(cols[3].parse::<i64>().unwrap_or(0));
+        line_num.push(cols[4].parse::<i64>().unwrap_or(0));
+        word_num.push(cols[5].parse::<i64>().unwrap_or(0));
+        left.push(cols[6].parse::<i64>().unwrap_or(0));
+        top.push(cols[7].parse::<i64>().unwrap_or(0));
+        width.push(cols[8].parse::<i64>().unwrap_or(0));
+        height.push(cols[9].parse::<i64>().unwrap_or(0));
+        conf.push(cols[10].parse::<f64>().unwrap_or(-1.0));
+        text.push(cols[11].to_string());
+    }
+
+    DataFrame::new(vec![
+        Series::new("level", level),
+        Series::new("page_num", page_num),
+        Series::new("block_num", block_num),
+        Series::new("par_num", par_num),
+        Series::new("line_num", line_num),
+        Series::new("word_num", word_num),
+        Series::new("left", left),
+        Series::new("top", top),
+        Series::new("width", width),
+        Series::new("height", height),
+        Series::new("conf", conf),
+        Series::new("text", text),
+    ])
+    .unwrap_or_default()
+}
+
 mod tests {
 
     #[test]
@@ -543,17 +842,10 @@ mod tests {
             ndarray: ndarray::Array3::<u8>::zeros((200, 200, 3)), // example: creates an 100x100 pixel image with 3 colour channels (RGB)
         };
 
-        // default_args.lang = "chi_sim";
-        let mut image_to_string_args = super::Args {
-            out_filename: "out",
-            lang: "chi_sim",
-            config: std::collections::HashMap::new(),
-            dpi: 150,
-            boxfile: false,
-        };
-
-        image_to_string_args.config.insert("psm", "6");
-        image_to_string_args.config.insert("oem", "3");
+        let mut image_to_string_args = super::Args::new();
+        image_to_string_args.lang = String::from("chi_sim");
+        image_to_string_args.psm = super::PageSegMode::SingleBlock;
+        image_to_string_args.oem = super::OcrEngineMode::Default;
 
         let output = crate::rust_tesseract::image_to_string(&img, image_to_string_args);
         println!("\nThe String output is: {:?}", output.output_string);